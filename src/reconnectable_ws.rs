@@ -3,11 +3,38 @@ use crate::exchange_ws::{CallbackHandle, ExchangeWs, OpenLimitsWs, Subscriptions
 use crate::model::websocket::{Subscription, WebSocketResponse};
 use crate::shared::Result;
 use futures::stream::BoxStream;
+use futures_util::StreamExt;
+use rand::Rng;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::thread::sleep;
+use std::time::Instant;
+use tokio::sync::broadcast;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
 use tokio::sync::Mutex;
 use tokio::time::Duration;
+use tokio_stream::wrappers::BroadcastStream;
+
+/// Size of the [`ConnectionState`] broadcast channel. Slow subscribers that fall this far behind
+/// simply miss intermediate states rather than stalling the reconnection loop.
+const CONNECTION_EVENTS_CAPACITY: usize = 16;
+
+/// Lifecycle state of the underlying websocket connection, emitted on
+/// [`ReconnectableWebsocket::connection_events`] as the reconnection loop progresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Disconnected,
+    Reconnecting { attempt: u32 },
+    Reconnected,
+    /// Emitted once `on_reconnect` has run, after subscriptions are re-established. Signals that
+    /// any locally cached state (e.g. an order book) is stale and should be rebuilt from a fresh
+    /// REST snapshot before further diffs are applied.
+    Resynced,
+}
+
+/// Invoked by the reconnection loop after all subscriptions are re-established, so consumers can
+/// rebuild state (e.g. an order book) from a fresh REST snapshot before applying further diffs.
+pub type OnReconnect = Arc<dyn Fn() + Send + Sync + 'static>;
 
 pub type SubscriptionCallback<Response> =
     Arc<dyn Fn(&Result<WebSocketResponse<Response>>) + Sync + Send + 'static>;
@@ -17,30 +44,332 @@ pub type SubscriptionCallbackRegistry<E> = (
     SubscriptionCallback<<E as ExchangeWs>::Response>,
 );
 
+/// Identifies a single live subscription so it can later be removed with
+/// [`ReconnectableWebsocket::unsubscribe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+/// Controls how aggressively [`ReconnectableWebsocket`] retries a dropped connection.
+///
+/// Delays follow a simple exponential backoff: starting at `initial`, each failed attempt
+/// multiplies the delay by `multiplier` (capped at `max`), randomized by `+/- jitter` to avoid
+/// every client hammering the exchange back to life at the same instant. The delay resets to
+/// `initial` once a reconnection fully succeeds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReconnectConfig {
+    pub initial: Duration,
+    pub max: Duration,
+    pub multiplier: f64,
+    /// Fractional randomization applied to each delay, e.g. `0.2` for `+/- 20%`. Treated as its
+    /// absolute value, so a negative `jitter` behaves the same as its positive counterpart rather
+    /// than producing an inverted range that would panic and wedge reconnection forever.
+    pub jitter: f64,
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: 0.2,
+            max_attempts: None,
+        }
+    }
+}
+
+/// Records that a message was just seen, for the heartbeat watchdog to compare against.
+fn touch_heartbeat(last_message_seen: &(Instant, AtomicU64)) {
+    let now_millis = last_message_seen.0.elapsed().as_millis() as u64;
+    last_message_seen.1.store(now_millis, Ordering::SeqCst);
+}
+
+impl ReconnectConfig {
+    fn jittered(&self, delay: Duration) -> Duration {
+        let jitter = self.jitter.abs();
+        let jitter = rand::thread_rng().gen_range(-jitter..=jitter);
+        delay.mul_f64((1.0 + jitter).max(0.0))
+    }
+
+    fn next_delay(&self, delay: Duration) -> Duration {
+        delay.mul_f64(self.multiplier).min(self.max)
+    }
+}
+
+/// Overflow policy applied once a subscription's delivery channel reaches `capacity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Apply backpressure: the exchange's read loop waits until the consumer catches up.
+    ///
+    /// Implemented with [`tokio::task::block_in_place`], which requires a multi-thread runtime
+    /// to make progress. Under a `current_thread` runtime (e.g. a single-threaded test harness,
+    /// or `#[tokio::main(flavor = "current_thread")]`) there is no other worker to pick up the
+    /// task being blocked, so rather than panicking and silently killing message delivery for the
+    /// subscription, a full channel falls back to [`Overflow::DropOldest`] behavior instead.
+    Block,
+    /// Keep the newest market data and discard the oldest queued message.
+    DropOldest,
+}
+
+/// Bounds a subscription's callback dispatch queue, so a slow consumer can no longer cause
+/// unbounded memory growth during a burst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeliveryConfig {
+    pub capacity: usize,
+    pub overflow: Overflow,
+}
+
+impl Default for DeliveryConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 1024,
+            overflow: Overflow::DropOldest,
+        }
+    }
+}
+
+/// A bounded, single-consumer delivery queue for one subscription's callback dispatch.
+///
+/// `push` is synchronous because it is called from the exchange's message-handling callback,
+/// which runs on a tokio worker thread and must never be `.await`ed directly. Waiting for room
+/// under `Overflow::Block` therefore goes through [`tokio::task::block_in_place`] plus
+/// `Handle::block_on`, tokio's documented bridge for driving async waits from sync code: it
+/// marks the current worker as blocked so the runtime can schedule another one in its place.
+/// See [`Overflow::Block`] for what happens on a `current_thread` runtime, where that bridge
+/// cannot make progress.
+struct DeliveryChannel<T> {
+    queue: std::sync::Mutex<VecDeque<T>>,
+    capacity: usize,
+    overflow: Overflow,
+    not_full: tokio::sync::Notify,
+    not_empty: tokio::sync::Notify,
+    dropped: Arc<AtomicU64>,
+    closed: AtomicBool,
+}
+
+impl<T> DeliveryChannel<T> {
+    fn new(config: DeliveryConfig) -> Self {
+        Self {
+            queue: std::sync::Mutex::new(VecDeque::with_capacity(config.capacity)),
+            capacity: config.capacity.max(1),
+            overflow: config.overflow,
+            not_full: tokio::sync::Notify::new(),
+            not_empty: tokio::sync::Notify::new(),
+            dropped: Arc::new(AtomicU64::new(0)),
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    fn push(&self, item: T) {
+        loop {
+            let mut queue = self.queue.lock().unwrap();
+            if self.closed.load(Ordering::SeqCst) {
+                return;
+            }
+            if queue.len() < self.capacity {
+                queue.push_back(item);
+                drop(queue);
+                self.not_empty.notify_one();
+                return;
+            }
+            match self.overflow {
+                Overflow::DropOldest => {
+                    queue.pop_front();
+                    self.dropped.fetch_add(1, Ordering::SeqCst);
+                    queue.push_back(item);
+                    drop(queue);
+                    self.not_empty.notify_one();
+                    return;
+                }
+                Overflow::Block => {
+                    // `block_in_place` has no other worker to fall back to on a `current_thread`
+                    // runtime and would panic there, killing message delivery for this
+                    // subscription. Degrade to `DropOldest` instead of crashing.
+                    let is_multi_thread = tokio::runtime::Handle::try_current()
+                        .map(|handle| {
+                            handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread
+                        })
+                        .unwrap_or(false);
+                    if !is_multi_thread {
+                        queue.pop_front();
+                        self.dropped.fetch_add(1, Ordering::SeqCst);
+                        queue.push_back(item);
+                        drop(queue);
+                        self.not_empty.notify_one();
+                        return;
+                    }
+                    // Register interest before releasing the lock: any `pop` that could free up
+                    // room must take this same lock first, so its wakeup can't be missed.
+                    let notified = self.not_full.notified();
+                    drop(queue);
+                    tokio::task::block_in_place(|| {
+                        tokio::runtime::Handle::current().block_on(notified);
+                    });
+                }
+            }
+        }
+    }
+
+    async fn pop(&self) -> Option<T> {
+        loop {
+            {
+                let mut queue = self.queue.lock().unwrap();
+                if let Some(item) = queue.pop_front() {
+                    drop(queue);
+                    self.not_full.notify_one();
+                    return Some(item);
+                }
+                if self.closed.load(Ordering::SeqCst) {
+                    return None;
+                }
+            }
+            self.not_empty.notified().await;
+        }
+    }
+
+    fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::SeqCst)
+    }
+
+    fn dropped_counter(&self) -> Arc<AtomicU64> {
+        self.dropped.clone()
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+        self.not_empty.notify_waiters();
+        self.not_full.notify_waiters();
+    }
+}
+
+/// Dropped-message counter for a bounded stream returned by
+/// [`ReconnectableWebsocket::create_stream`] /
+/// [`ReconnectableWebsocket::create_stream_specific`], for monitoring
+/// consumer lag. Stays zero under [`Overflow::Block`] on a multi-thread runtime; only advances
+/// there if the `current_thread`-runtime fallback described on that variant kicks in.
+#[derive(Clone)]
+pub struct DeliveryStats {
+    dropped: Arc<AtomicU64>,
+}
+
+impl DeliveryStats {
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::SeqCst)
+    }
+}
+
+/// Wraps a stream so it is pulled through a bounded [`DeliveryChannel`] respecting `delivery`,
+/// instead of letting a slow consumer buffer an unbounded backlog of the exchange's messages.
+fn bounded_stream<T: Send + 'static>(
+    mut inner: BoxStream<'static, T>,
+    delivery: DeliveryConfig,
+) -> (BoxStream<'static, T>, DeliveryStats) {
+    let channel = Arc::new(DeliveryChannel::new(delivery));
+    let stats = DeliveryStats {
+        dropped: channel.dropped_counter(),
+    };
+    {
+        let channel = channel.clone();
+        tokio::spawn(async move {
+            while let Some(item) = inner.next().await {
+                channel.push(item);
+            }
+            channel.close();
+        });
+    }
+    let stream = futures_util::stream::unfold(channel, |channel| async move {
+        channel.pop().await.map(|item| (item, channel))
+    })
+    .boxed();
+    (stream, stats)
+}
+
 pub struct ReconnectableWebsocket<E: ExchangeWs> {
     websocket: Arc<Mutex<OpenLimitsWs<E>>>,
     tx: UnboundedSender<()>,
-    subscriptions: Arc<Mutex<Vec<SubscriptionCallbackRegistry<E>>>>,
+    subscriptions: Arc<Mutex<HashMap<u64, SubscriptionCallbackRegistry<E>>>>,
+    next_subscription_id: Arc<AtomicU64>,
+    connection_events: broadcast::Sender<ConnectionState>,
+    last_message_seen: Arc<(Instant, AtomicU64)>,
+    delivery_channels:
+        Arc<Mutex<HashMap<u64, Arc<DeliveryChannel<Result<WebSocketResponse<E::Response>>>>>>>,
+    /// Owns the low-level callback handle for each live subscription, so `unsubscribe` has
+    /// something to drop: dropping the handle is what actually stops the exchange from calling
+    /// back into a subscription that has been removed.
+    handles: Arc<Mutex<HashMap<u64, CallbackHandle>>>,
 }
 
 impl<E: ExchangeWs + 'static> ReconnectableWebsocket<E> {
-    pub async fn instantiate(params: E::InitParams, reattempt_interval: Duration) -> Result<Self> {
+    pub async fn instantiate(
+        params: E::InitParams,
+        reconnect: ReconnectConfig,
+        heartbeat_timeout: Option<Duration>,
+        on_reconnect: Option<OnReconnect>,
+    ) -> Result<Self> {
         let websocket = E::new(params.clone()).await?;
         let websocket = OpenLimitsWs { websocket };
         let websocket = Arc::new(Mutex::new(websocket));
-        let subscriptions: Arc<Mutex<Vec<SubscriptionCallbackRegistry<E>>>> =
+        let subscriptions: Arc<Mutex<HashMap<u64, SubscriptionCallbackRegistry<E>>>> =
+            Arc::new(Mutex::new(Default::default()));
+        let handles: Arc<Mutex<HashMap<u64, CallbackHandle>>> =
             Arc::new(Mutex::new(Default::default()));
         let (tx, mut rx) = unbounded_channel();
+        let (connection_events, _) = broadcast::channel(CONNECTION_EVENTS_CAPACITY);
+        let last_message_seen = Arc::new((Instant::now(), AtomicU64::new(0)));
+        let reconnecting = Arc::new(AtomicBool::new(false));
+        if let Some(heartbeat_timeout) = heartbeat_timeout {
+            let last_message_seen = Arc::downgrade(&last_message_seen);
+            let reconnecting = reconnecting.clone();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(heartbeat_timeout);
+                interval.tick().await;
+                loop {
+                    interval.tick().await;
+                    let Some(last_message_seen) = last_message_seen.upgrade() else {
+                        break;
+                    };
+                    // A reconnection already in flight will itself resubscribe and touch the
+                    // heartbeat once healthy; firing again here would only queue a redundant
+                    // signal that the outer loop would later mistake for a fresh disconnection.
+                    if reconnecting.load(Ordering::SeqCst) {
+                        continue;
+                    }
+                    let last_seen_millis = last_message_seen.1.load(Ordering::SeqCst);
+                    let now_millis = last_message_seen.0.elapsed().as_millis() as u64;
+                    if now_millis.saturating_sub(last_seen_millis) >= heartbeat_timeout.as_millis() as u64 {
+                        tx.send(()).ok();
+                    }
+                }
+            });
+        }
         {
             let websocket = Arc::downgrade(&websocket);
             let subscriptions = Arc::downgrade(&subscriptions);
+            let handles = Arc::downgrade(&handles);
             let tx = tx.clone();
+            let connection_events = connection_events.clone();
+            let last_message_seen = Arc::downgrade(&last_message_seen);
+            let on_reconnect = on_reconnect.clone();
             tokio::spawn(async move {
                 while rx.recv().await.is_some() {
+                    reconnecting.store(true, Ordering::SeqCst);
+                    // Drop any further signals that piled up while this episode was already
+                    // underway (e.g. from in-flight subscription callbacks racing the watchdog),
+                    // so a single outage can't be replayed as several once we're done.
+                    while rx.try_recv().is_ok() {}
+                    connection_events.send(ConnectionState::Disconnected).ok();
+                    let mut delay = reconnect.initial;
+                    let mut attempt = 0u32;
                     'reconnection: loop {
                         if let (Some(websocket), Some(subscriptions)) =
                             (websocket.upgrade(), subscriptions.upgrade())
                         {
+                            attempt += 1;
+                            connection_events
+                                .send(ConnectionState::Reconnecting { attempt })
+                                .ok();
                             if let Ok(new_websocket) = E::new(params.clone()).await {
                                 let new_websocket = OpenLimitsWs {
                                     websocket: new_websocket,
@@ -48,31 +377,70 @@ impl<E: ExchangeWs + 'static> ReconnectableWebsocket<E> {
                                 let mut websocket = websocket.lock().await;
                                 *websocket = new_websocket;
 
-                                let subscriptions = { subscriptions.lock().await.clone() };
-                                let subscriptions =
-                                    subscriptions.iter().map(|(subscription, callback)| {
-                                        let callback = callback.clone();
+                                let live_subscriptions = { subscriptions.lock().await.clone() };
+                                let resubscriptions =
+                                    live_subscriptions.into_iter().map(|(id, (subscription, callback))| {
                                         let tx = tx.clone();
-                                        websocket.subscribe(subscription.clone(), move |message| {
-                                            if let Err(OpenLimitsError::SocketError()) =
-                                                message.as_ref()
-                                            {
-                                                tx.send(()).ok();
-                                            }
-                                            callback(message)
-                                        })
+                                        let last_message_seen = last_message_seen.clone();
+                                        let handle_future =
+                                            websocket.subscribe(subscription, move |message| {
+                                                if let Some(last_message_seen) =
+                                                    last_message_seen.upgrade()
+                                                {
+                                                    touch_heartbeat(&last_message_seen);
+                                                }
+                                                if let Err(OpenLimitsError::SocketError()) =
+                                                    message.as_ref()
+                                                {
+                                                    tx.send(()).ok();
+                                                }
+                                                callback(message)
+                                            });
+                                        async move { (id, handle_future.await) }
                                     });
-                                if futures_util::future::join_all(subscriptions)
-                                    .await
-                                    .iter()
-                                    .all(|subscription| subscription.is_ok())
-                                {
+                                let resubscriptions =
+                                    futures_util::future::join_all(resubscriptions).await;
+                                if resubscriptions.iter().all(|(_, handle)| handle.is_ok()) {
+                                    if let Some(handles) = handles.upgrade() {
+                                        let mut handles = handles.lock().await;
+                                        for (id, handle) in resubscriptions {
+                                            if let Ok(handle) = handle {
+                                                handles.insert(id, handle);
+                                            }
+                                        }
+                                    }
+                                    // Restart the heartbeat clock from the moment of recovery:
+                                    // otherwise the stale timestamp that triggered this reconnect
+                                    // (still `>= heartbeat_timeout` old) would trip the watchdog
+                                    // again on its very next tick, forcing a needless second
+                                    // reconnect against an already-healthy socket.
+                                    if let Some(last_message_seen) = last_message_seen.upgrade() {
+                                        touch_heartbeat(&last_message_seen);
+                                    }
+                                    connection_events.send(ConnectionState::Reconnected).ok();
+                                    if let Some(on_reconnect) = &on_reconnect {
+                                        on_reconnect();
+                                    }
+                                    connection_events.send(ConnectionState::Resynced).ok();
+                                    break 'reconnection;
+                                }
+                            }
+
+                            if let Some(max_attempts) = reconnect.max_attempts {
+                                if attempt >= max_attempts {
+                                    let live_subscriptions =
+                                        { subscriptions.lock().await.clone() };
+                                    for (_, callback) in live_subscriptions.values() {
+                                        callback(&Err(OpenLimitsError::SocketError()));
+                                    }
                                     break 'reconnection;
                                 }
                             }
-                            sleep(reattempt_interval);
+                            tokio::time::sleep(reconnect.jittered(delay)).await;
+                            delay = reconnect.next_delay(delay);
                         }
                     }
+                    reconnecting.store(false, Ordering::SeqCst);
                 }
             });
         }
@@ -80,18 +448,34 @@ impl<E: ExchangeWs + 'static> ReconnectableWebsocket<E> {
             websocket,
             tx,
             subscriptions,
+            next_subscription_id: Arc::new(AtomicU64::new(0)),
+            connection_events,
+            last_message_seen,
+            delivery_channels: Arc::new(Mutex::new(Default::default())),
+            handles,
         })
     }
 
+    /// Streams connection-lifecycle events as the reconnection loop progresses, so callers can
+    /// pause order submission or resync state precisely when the socket flaps.
+    pub fn connection_events(&self) -> BoxStream<'static, ConnectionState> {
+        BroadcastStream::new(self.connection_events.subscribe())
+            .filter_map(|event| async move { event.ok() })
+            .boxed()
+    }
+
     pub async fn create_stream_specific(
         &self,
         subscriptions: Subscriptions<E::Subscription>,
-    ) -> Result<BoxStream<'static, Result<E::Response>>> {
-        self.websocket
+        delivery: DeliveryConfig,
+    ) -> Result<(BoxStream<'static, Result<E::Response>>, DeliveryStats)> {
+        let inner = self
+            .websocket
             .lock()
             .await
             .create_stream_specific(subscriptions)
-            .await
+            .await?;
+        Ok(bounded_stream(inner, delivery))
     }
 
     pub async fn subscribe<
@@ -99,37 +483,244 @@ impl<E: ExchangeWs + 'static> ReconnectableWebsocket<E> {
     >(
         &self,
         subscription: Subscription,
+        delivery: DeliveryConfig,
         callback: F,
-    ) -> Result<CallbackHandle> {
-        let tx = self.tx.clone();
+    ) -> Result<SubscriptionId>
+    where
+        E::Response: Clone,
+    {
+        let id = self.next_subscription_id.fetch_add(1, Ordering::SeqCst);
+        let channel = Arc::new(DeliveryChannel::new(delivery));
+        {
+            let channel = channel.clone();
+            tokio::spawn(async move {
+                while let Some(message) = channel.pop().await {
+                    callback(&message);
+                }
+            });
+        }
+        let dispatch: SubscriptionCallback<E::Response> = {
+            let channel = channel.clone();
+            Arc::new(move |message: &Result<WebSocketResponse<E::Response>>| {
+                channel.push(message.clone())
+            })
+        };
         self.subscriptions
             .lock()
             .await
-            .push((subscription.clone(), Arc::new(callback.clone())));
-        self.websocket
+            .insert(id, (subscription.clone(), dispatch.clone()));
+        self.delivery_channels.lock().await.insert(id, channel);
+
+        let tx = self.tx.clone();
+        let last_message_seen = self.last_message_seen.clone();
+        let handle = self
+            .websocket
             .lock()
             .await
             .subscribe(subscription, move |message| {
+                touch_heartbeat(&last_message_seen);
                 if let Err(OpenLimitsError::SocketError()) = message.as_ref() {
                     tx.send(()).ok();
                 }
-                callback(message);
+                dispatch(message);
             })
+            .await?;
+        self.handles.lock().await.insert(id, handle);
+        Ok(SubscriptionId(id))
+    }
+
+    /// Removes a subscription so it is no longer re-established on reconnect, drops the
+    /// underlying callback handle so its task stops, and closes its delivery channel.
+    pub async fn unsubscribe(&self, id: SubscriptionId) {
+        self.subscriptions.lock().await.remove(&id.0);
+        self.handles.lock().await.remove(&id.0);
+        if let Some(channel) = self.delivery_channels.lock().await.remove(&id.0) {
+            channel.close();
+        }
+    }
+
+    /// Number of messages dropped so far for a subscription using the `DropOldest` overflow
+    /// policy, for monitoring consumer lag. Stays zero under `Block` on a multi-thread runtime;
+    /// see [`Overflow::Block`] for the `current_thread` fallback that can advance it instead.
+    pub async fn dropped_count(&self, id: SubscriptionId) -> u64 {
+        self.delivery_channels
+            .lock()
             .await
+            .get(&id.0)
+            .map(|channel| channel.dropped())
+            .unwrap_or(0)
     }
 
     pub async fn create_stream<S: Into<E::Subscription> + Clone + Send + Sync>(
         &self,
         subscriptions: &[S],
-    ) -> Result<BoxStream<'static, Result<WebSocketResponse<E::Response>>>> {
-        self.websocket
+        delivery: DeliveryConfig,
+    ) -> Result<(
+        BoxStream<'static, Result<WebSocketResponse<E::Response>>>,
+        DeliveryStats,
+    )> {
+        let inner = self
+            .websocket
             .lock()
             .await
             .create_stream(subscriptions)
-            .await
+            .await?;
+        Ok(bounded_stream(inner, delivery))
     }
 
     pub async fn disconnect(&self) {
         self.websocket.lock().await.disconnect().await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_delay_grows_by_multiplier_and_caps_at_max() {
+        let config = ReconnectConfig {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.0,
+            max_attempts: None,
+        };
+        let delay = config.next_delay(Duration::from_secs(1));
+        assert_eq!(delay, Duration::from_secs(2));
+        let delay = config.next_delay(delay);
+        assert_eq!(delay, Duration::from_secs(4));
+        let delay = config.next_delay(delay);
+        assert_eq!(delay, Duration::from_secs(8));
+        // Would be 16s uncapped; `max` clamps it to 10s instead.
+        let delay = config.next_delay(delay);
+        assert_eq!(delay, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn jittered_stays_within_configured_bounds() {
+        let config = ReconnectConfig {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: 0.25,
+            max_attempts: None,
+        };
+        let delay = Duration::from_secs(10);
+        for _ in 0..1000 {
+            let jittered = config.jittered(delay);
+            assert!(jittered >= Duration::from_millis(7500));
+            assert!(jittered <= Duration::from_millis(12500));
+        }
+    }
+
+    #[test]
+    fn jittered_never_goes_negative_with_large_jitter() {
+        let config = ReconnectConfig {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: 1.0,
+            max_attempts: None,
+        };
+        let delay = Duration::from_secs(1);
+        for _ in 0..1000 {
+            let jittered = config.jittered(delay);
+            assert!(jittered >= Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn jittered_treats_negative_jitter_as_its_absolute_value() {
+        let config = ReconnectConfig {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: -0.25,
+            max_attempts: None,
+        };
+        let delay = Duration::from_secs(10);
+        for _ in 0..1000 {
+            // A naive `-jitter..=jitter` range would be inverted and panic; this must not.
+            let jittered = config.jittered(delay);
+            assert!(jittered >= Duration::from_millis(7500));
+            assert!(jittered <= Duration::from_millis(12500));
+        }
+    }
+
+    #[tokio::test]
+    async fn delivery_channel_pops_in_fifo_order() {
+        let channel = DeliveryChannel::new(DeliveryConfig {
+            capacity: 4,
+            overflow: Overflow::DropOldest,
+        });
+        channel.push(1);
+        channel.push(2);
+        channel.push(3);
+        assert_eq!(channel.pop().await, Some(1));
+        assert_eq!(channel.pop().await, Some(2));
+        assert_eq!(channel.pop().await, Some(3));
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_evicts_the_front_and_counts_drops() {
+        let channel = DeliveryChannel::new(DeliveryConfig {
+            capacity: 2,
+            overflow: Overflow::DropOldest,
+        });
+        channel.push(1);
+        channel.push(2);
+        // Over capacity: evicts `1`, keeping the newest two.
+        channel.push(3);
+        assert_eq!(channel.dropped(), 1);
+        assert_eq!(channel.pop().await, Some(2));
+        assert_eq!(channel.pop().await, Some(3));
+    }
+
+    #[tokio::test]
+    async fn close_wakes_a_pending_pop_with_none() {
+        let channel = Arc::new(DeliveryChannel::<u32>::new(DeliveryConfig {
+            capacity: 4,
+            overflow: Overflow::DropOldest,
+        }));
+        let popper = {
+            let channel = channel.clone();
+            tokio::spawn(async move { channel.pop().await })
+        };
+        // Give the pop task a chance to register its wait before closing.
+        tokio::task::yield_now().await;
+        channel.close();
+        assert_eq!(popper.await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn push_is_a_no_op_once_closed() {
+        let channel = DeliveryChannel::new(DeliveryConfig {
+            capacity: 4,
+            overflow: Overflow::DropOldest,
+        });
+        channel.close();
+        channel.push(1);
+        assert_eq!(channel.pop().await, None);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn block_overflow_waits_for_room_instead_of_dropping() {
+        let channel = Arc::new(DeliveryChannel::new(DeliveryConfig {
+            capacity: 1,
+            overflow: Overflow::Block,
+        }));
+        channel.push(1);
+        let pusher = {
+            // `push`'s `Block` branch calls `block_in_place`, which requires running on a
+            // multi-thread worker rather than inside a dedicated blocking-pool thread.
+            let channel = channel.clone();
+            tokio::spawn(async move { channel.push(2) })
+        };
+        // The pusher is blocked because the channel is full; popping the first item unblocks it.
+        assert_eq!(channel.pop().await, Some(1));
+        pusher.await.unwrap();
+        assert_eq!(channel.pop().await, Some(2));
+        assert_eq!(channel.dropped(), 0);
+    }
+}